@@ -10,12 +10,22 @@ use std::convert::From;
 use base64;
 use json;
 use json::JsonValue;
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use aes_gcm::aead::{Aead, NewAead};
+use sha2::{Digest, Sha256};
 
 pub trait Config {
     fn get(&self, key: &str) -> Option<Value>;
-    fn set<T: Into<Value>>(&mut self, key: &str, value: T);
+    fn set<T: Into<Value>>(&mut self, key: &str, value: T) where Self: Sized;
     fn delete(&mut self, key: &str);
 
+    /// Writes a secret value such as a refresh_token. Implementations store it
+    /// encrypted with an "enc:" prefix.
+    fn set_secret<T: Into<Value>>(&mut self, key: &str, value: T) where Self: Sized;
+    /// Decrypts a value written by set_secret. Existing values without the
+    /// "enc:" prefix are returned as-is (plaintext).
+    fn get_secret(&self, key: &str) -> Option<String>;
+
     fn get_str(&self, key: &str) -> Option<String> {
         self.get(key).and_then(|v| match v {
             Value::String(x) => Some(x),
@@ -112,6 +122,81 @@ pub enum JsonConfigError {
 }
 
 const BASE64_PREFIX: &'static str = "base64:";
+const ENC_PREFIX: &'static str = "enc:";
+const NONCE_LEN: usize = 12;
+const MASTER_KEY_FILE: &'static str = "master.key";
+const MASTER_KEY_PASSPHRASE_ENV: &'static str = "SCIURUS_CONFIG_PASSPHRASE";
+
+// If a passphrase env var is set, hash it into a key. Otherwise read
+// master.key from the config dir, generating and saving one if missing
+// (a stand-in for an OS-provided secret store).
+fn master_key() -> [u8; 32] {
+    if let Ok(passphrase) = std::env::var(MASTER_KEY_PASSPHRASE_ENV) {
+        let mut hasher = Sha256::new();
+        hasher.input(passphrase.as_bytes());
+        let digest = hasher.result();
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&digest);
+        return key;
+    }
+    load_or_create_key_file()
+}
+
+fn load_or_create_key_file() -> [u8; 32] {
+    let mut path = get_config_dir_path();
+    let _ = std::fs::create_dir_all(&path);
+    path.push(MASTER_KEY_FILE);
+
+    if let Ok(mut file) = File::open(&path) {
+        let mut key = [0u8; 32];
+        if file.read_exact(&mut key).is_ok() {
+            return key;
+        }
+    }
+
+    let mut key = [0u8; 32];
+    read_random(&mut key);
+    let _ = File::create(&path).and_then(|mut f| f.write_all(&key));
+    key
+}
+
+fn read_random(buf: &mut [u8]) {
+    File::open("/dev/urandom")
+        .and_then(|mut f| f.read_exact(buf))
+        .expect("failed to read /dev/urandom")
+}
+
+fn encrypt_value(plaintext: &str) -> String {
+    let key_bytes = master_key();
+    let cipher = Aes256Gcm::new(Key::from_slice(&key_bytes));
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    read_random(&mut nonce_bytes);
+
+    let ciphertext = cipher.encrypt(Nonce::from_slice(&nonce_bytes), plaintext.as_bytes())
+        .expect("AES-GCM encryption failed");
+
+    let mut payload = nonce_bytes.to_vec();
+    payload.extend_from_slice(&ciphertext);
+    ENC_PREFIX.to_string() + &base64::encode(&payload)
+}
+
+// Returns None on decryption or auth-tag verification failure (never panics)
+fn decrypt_value(stored: &str) -> Option<String> {
+    let payload = match base64::decode(&stored[ENC_PREFIX.len()..]) {
+        Ok(x) => x,
+        Err(_) => return None,
+    };
+    if payload.len() <= NONCE_LEN {
+        return None;
+    }
+    let (nonce_bytes, ciphertext) = payload.split_at(NONCE_LEN);
+
+    let key_bytes = master_key();
+    let cipher = Aes256Gcm::new(Key::from_slice(&key_bytes));
+    cipher.decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+}
 
 impl JsonConfig {
     pub fn new<T: AsRef<Path>>(path: T, auto_save: bool) -> Self {
@@ -221,6 +306,33 @@ impl Config for JsonConfig {
             self.save().unwrap();
         }
     }
+
+    fn set_secret<T: Into<Value>>(&mut self, key: &str, value: T) {
+        let plaintext = match value.into() {
+            Value::String(x) => x,
+            Value::Bytes(x) => base64::encode(&x),
+            Value::Number(x) => x.to_string(),
+            Value::Bool(x) => x.to_string(),
+            Value::Null => String::new(),
+        };
+        *self.lookup_mut(key) = JsonValue::String(encrypt_value(&plaintext));
+        if self.auto_save {
+            self.save().unwrap();
+        }
+    }
+
+    fn get_secret(&self, key: &str) -> Option<String> {
+        let raw = match *self.lookup(key) {
+            JsonValue::Short(ref x) => x.to_string(),
+            JsonValue::String(ref x) => x.to_string(),
+            _ => return None,
+        };
+        if raw.starts_with(ENC_PREFIX) {
+            decrypt_value(&raw)
+        } else {
+            Some(raw)
+        }
+    }
 }
 
 #[cfg(target_os="linux")]
@@ -256,6 +368,16 @@ fn test() {
         assert_eq!(b"Hello World".to_vec(),
                    config.get_bytes("hoge.raw").unwrap());
         assert!(config.get("foobar").is_none());
+
+        config.set_secret("hoge.secret", "s3cr3t");
+        assert_eq!("s3cr3t", config.get_secret("hoge.secret").unwrap());
+        assert!(config.get_str("hoge.secret").unwrap().starts_with(ENC_PREFIX));
+
+        config.set("hoge.plain_secret", "legacy");
+        assert_eq!("legacy", config.get_secret("hoge.plain_secret").unwrap());
+
+        assert!(decrypt_value("enc:not-valid-base64!!").is_none());
+
         config.save().unwrap();
     }
     {
@@ -267,6 +389,7 @@ fn test() {
         assert_eq!(true, config.get_bool("hoge.flag0").unwrap());
         assert_eq!(false, config.get_bool("hoge.flag1").unwrap());
         assert!(config.get("foobar").is_none());
+        assert_eq!("s3cr3t", config.get_secret("hoge.secret").unwrap());
         config.set("hoge.piyo", "bar");
         config.set("test", "helloworld");
     }
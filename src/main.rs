@@ -4,6 +4,8 @@
 extern crate json;
 extern crate hyper;
 extern crate base64;
+extern crate aes_gcm;
+extern crate sha2;
 
 use std::sync::Arc;
 use std::sync::RwLock;
@@ -22,8 +24,5 @@ fn main() {
     }));
     config.write().unwrap().load().unwrap();
 
-    let mut onedrive = objectstore::OneDriveClient::new(env!("SCIURUS_ONEDRIVE_CLIENT_ID")
-                                                            .to_string(),
-                                                        config.clone());
-    onedrive.access_test();
+    objectstore::build(objectstore::Scheme::OneDrive, config.clone());
 }
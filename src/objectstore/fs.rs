@@ -0,0 +1,115 @@
+use std::fs::{self, File};
+use std::io;
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+use ::objectstore::ObjectStore;
+
+/// ObjectStore implementation backed by the local filesystem.
+/// Reads and writes files rooted at the directory given by `root`.
+pub struct FsObjectStore {
+    root: PathBuf,
+}
+
+impl FsObjectStore {
+    pub fn new<P: Into<PathBuf>>(root: P) -> FsObjectStore {
+        FsObjectStore { root: root.into() }
+    }
+
+    // Confines `name` to `root`: a leading `/` would make PathBuf::join discard
+    // `root` entirely, and a `..` component would let the caller walk back out
+    // of it, so both are rejected rather than joined.
+    fn resolve(&self, name: &str) -> io::Result<PathBuf> {
+        let path = Path::new(name);
+        let escapes = path.components().any(|c| match c {
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => true,
+            Component::CurDir | Component::Normal(_) => false,
+        });
+        if escapes {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                       format!("object name escapes store root: {}", name)));
+        }
+        Ok(self.root.join(path))
+    }
+}
+
+impl ObjectStore for FsObjectStore {
+    type Reader = File;
+    type Writer = File;
+    type ObjectIterator = FsObjectIterator;
+
+    fn open(&self, name: &str) -> io::Result<File> {
+        File::open(try!(self.resolve(name)))
+    }
+
+    fn create(&self, name: &str) -> io::Result<File> {
+        let path = try!(self.resolve(name));
+        if let Some(parent) = path.parent() {
+            try!(fs::create_dir_all(parent));
+        }
+        File::create(path)
+    }
+
+    fn remove(&self, name: &str) -> io::Result<()> {
+        fs::remove_file(try!(self.resolve(name)))
+    }
+
+    fn list(&self, prefix: &str) -> io::Result<FsObjectIterator> {
+        let dir = try!(self.resolve(prefix));
+        let entries = try!(fs::read_dir(dir));
+        Ok(FsObjectIterator { entries: entries })
+    }
+}
+
+pub struct FsObjectIterator {
+    entries: fs::ReadDir,
+}
+
+impl Iterator for FsObjectIterator {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        loop {
+            match self.entries.next() {
+                Some(Ok(entry)) => {
+                    if let Ok(name) = entry.file_name().into_string() {
+                        return Some(name);
+                    }
+                    // Skip entries whose native OS string isn't valid UTF-8
+                }
+                Some(Err(_)) => continue,
+                None => return None,
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    let root = "fs_objectstore_test_dir";
+    let _ = fs::remove_dir_all(root);
+    let store = FsObjectStore::new(root);
+
+    {
+        let mut writer = store.create("sub/hello.txt").unwrap();
+        writer.write_all(b"hello world").unwrap();
+    }
+
+    let mut contents = String::new();
+    store.open("sub/hello.txt").unwrap().read_to_string(&mut contents).unwrap();
+    assert_eq!("hello world", contents);
+
+    let names: Vec<String> = store.list("sub").unwrap().collect();
+    assert_eq!(vec!["hello.txt".to_string()], names);
+
+    assert!(store.open("sub/missing.txt").is_err());
+
+    store.remove("sub/hello.txt").unwrap();
+    assert!(store.open("sub/hello.txt").is_err());
+
+    assert!(store.open("/etc/passwd").is_err());
+    assert!(store.open("../../etc/passwd").is_err());
+    assert!(store.open("sub/../../etc/passwd").is_err());
+
+    fs::remove_dir_all(root).unwrap();
+}
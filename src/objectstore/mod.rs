@@ -2,17 +2,104 @@ use std::io::Read;
 use std::io::Result;
 use std::io::Seek;
 use std::io::Write;
+use std::sync::{Arc, RwLock};
 
 mod onedrive;
+mod fs;
 pub use self::onedrive::OneDriveClient;
+pub use self::fs::FsObjectStore;
+
+use ::config::Config;
 
 pub trait ObjectStore {
     type Reader: Read + Seek;
     type Writer: Write;
     type ObjectIterator: Iterator<Item = String>;
 
-    fn open(&self, name: AsRef<str>) -> Result<Self::Reader>;
-    fn create(&self, name: AsRef<str>) -> Result<Self::Writer>;
-    fn remove(&self, name: AsRef<str>) -> Result<()>;
-    fn list(&self, prefix: AsRef<str>) -> Result<Self::ObjectIterator>;
+    fn open(&self, name: &str) -> Result<Self::Reader>;
+    fn create(&self, name: &str) -> Result<Self::Writer>;
+    fn remove(&self, name: &str) -> Result<()>;
+    fn list(&self, prefix: &str) -> Result<Self::ObjectIterator>;
+}
+
+/// Read + Seek can't be combined directly in a trait object (only one
+/// non-auto trait is allowed), so give them a combined marker trait instead.
+pub trait ReadSeek: Read + Seek {}
+impl<T: Read + Seek> ReadSeek for T {}
+
+/// dyn-compatible version of ObjectStore with its associated types
+/// (Reader/Writer/ObjectIterator) erased. Any ObjectStore implementor whose
+/// Reader, Writer and ObjectIterator are 'static gets this automatically via
+/// the blanket impl below.
+pub trait DynObjectStore {
+    fn open(&self, name: &str) -> Result<Box<ReadSeek>>;
+    fn create(&self, name: &str) -> Result<Box<Write>>;
+    fn remove(&self, name: &str) -> Result<()>;
+    fn list(&self, prefix: &str) -> Result<Box<Iterator<Item = String>>>;
+}
+
+impl<T> DynObjectStore for T
+    where T: ObjectStore,
+          T::Reader: 'static,
+          T::Writer: 'static,
+          T::ObjectIterator: 'static
+{
+    fn open(&self, name: &str) -> Result<Box<ReadSeek>> {
+        ObjectStore::open(self, name).map(|r| Box::new(r) as Box<ReadSeek>)
+    }
+
+    fn create(&self, name: &str) -> Result<Box<Write>> {
+        ObjectStore::create(self, name).map(|w| Box::new(w) as Box<Write>)
+    }
+
+    fn remove(&self, name: &str) -> Result<()> {
+        ObjectStore::remove(self, name)
+    }
+
+    fn list(&self, prefix: &str) -> Result<Box<Iterator<Item = String>>> {
+        ObjectStore::list(self, prefix).map(|it| Box::new(it) as Box<Iterator<Item = String>>)
+    }
+}
+
+/// The kinds of backend build() can construct
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    OneDrive,
+    Fs,
+}
+
+impl Scheme {
+    pub fn parse(s: &str) -> Option<Scheme> {
+        match s {
+            "onedrive" => Some(Scheme::OneDrive),
+            "fs" => Some(Scheme::Fs),
+            _ => None,
+        }
+    }
+}
+
+/// Builds the ObjectStore matching scheme from options. OneDrive reads its
+/// client id and token through options (no root path); Fs reads its root
+/// path from options' "fs.root".
+pub fn build<TConfig>(scheme: Scheme, options: Arc<RwLock<TConfig>>) -> Box<DynObjectStore>
+    where TConfig: Config + 'static
+{
+    match scheme {
+        Scheme::OneDrive => {
+            let client_id = options.read()
+                .unwrap()
+                .get_str("onedrive.client_id")
+                .unwrap_or_else(|| env!("SCIURUS_ONEDRIVE_CLIENT_ID").to_string());
+            let client = OneDriveClient::new(client_id, options);
+            client.access_test();
+            Box::new(client)
+        }
+        Scheme::Fs => {
+            let root = options.read()
+                .unwrap()
+                .get_str("fs.root")
+                .unwrap_or_else(|| ".".to_string());
+            Box::new(FsObjectStore::new(root))
+        }
+    }
 }
@@ -1,77 +1,84 @@
-use std::io::Read;
+use std::fs::{self, File};
+use std::io::{BufRead, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
-use hyper::client::Client;
-use hyper::header::ContentType;
+use std::time::{Duration, Instant};
+use hyper::client::{Client, Response};
+use hyper::header::{Authorization, Bearer, ContentType};
+use hyper::status::StatusCode;
 use json;
 use ::config::Config;
+use ::objectstore::ObjectStore;
 
 const DESKTOP_APP_URI: &'static str = "https%3A%2F%2Flogin.live.com%2Foauth20_desktop.srf";
 const SCOPE: &'static str = "onedrive.readwrite%20offline_access";
+const GRAPH_DRIVE_ROOT: &'static str = "https://graph.microsoft.com/v1.0/me/drive";
 
-pub struct OneDriveClient<TConfig: Config> {
-    client: Client,
-    client_id: String,
-    config: Arc<RwLock<TConfig>>,
+// Largest file OneDrive's simple upload (PUT /content) will accept
+const SMALL_FILE_THRESHOLD: u64 = 4 * 1024 * 1024;
+// Size of each upload-session chunk; all but the final chunk must be a multiple of 320KiB
+const UPLOAD_CHUNK_SIZE: u64 = 320 * 1024 * 10;
+const UPLOAD_CHUNK_RETRIES: u32 = 3;
+// Treat the access token as expired this many seconds before it actually is
+const TOKEN_EXPIRY_MARGIN_SECS: u64 = 60;
+
+struct TokenState {
     access_token: String,
     refresh_token: String,
-    expires_in: u32,
     user_id: String,
+    expires_at: Option<Instant>,
 }
 
-impl<TConfig: Config> OneDriveClient<TConfig> {
-    pub fn new(client_id: String, config: Arc<RwLock<TConfig>>) -> OneDriveClient<TConfig> {
+/// Hub for the access/refresh token lifecycle. All Graph API requests go
+/// through authorized_request, which refreshes ahead of a known expiry and
+/// retries once, after a single refresh, on a 401.
+struct TokenManager<TConfig: Config> {
+    client: Arc<Client>,
+    client_id: String,
+    config: Arc<RwLock<TConfig>>,
+    state: RwLock<TokenState>,
+}
+
+impl<TConfig: Config> TokenManager<TConfig> {
+    fn new(client: Arc<Client>, client_id: String, config: Arc<RwLock<TConfig>>) -> Self {
         let refresh_token =
-            config.read().unwrap().get_str("onedrive.refresh_token").unwrap_or_default();
-        OneDriveClient {
-            client: Client::new(),
+            config.read().unwrap().get_secret("onedrive.refresh_token").unwrap_or_default();
+        TokenManager {
+            client: client,
             client_id: client_id,
             config: config,
-            access_token: String::new(),
-            refresh_token: refresh_token,
-            expires_in: 0,
-            user_id: String::new(),
-        }
-    }
-
-    pub fn access_test(&mut self) {
-        if self.refresh_token.is_empty() {
-            let code = {
-                self.config.read().unwrap().get_str("onedrive.code").unwrap_or_default()
-            };
-            if !code.is_empty() {
-                self.config.write().unwrap().delete("onedrive.code");
-                let post_body = format!("client_id={client_id}&redirect_uri={redirect_uri}&grant_type=authorization_code&code={code}",
-                                        client_id = self.client_id,
-                                        redirect_uri = DESKTOP_APP_URI,
-                                        code = code);
-                if self._update_access_token(post_body).is_ok() {
-                    return;
-                }
-            }
-
-            // TODO: panic以外にいい方法あれば...
-            let ep = format!("https://login.live.com/oauth20_authorize.\
-                              srf?client_id={client_id}&scope={scope}&response_type=code&redirect_uri={redirect_uri}",
-                             client_id = self.client_id,
-                             scope = SCOPE,
-                             redirect_uri = DESKTOP_APP_URI);
-            self.config.write().unwrap().set("onedrive.code", ep);
-            panic!("required authorization_code");
+            state: RwLock::new(TokenState {
+                access_token: String::new(),
+                refresh_token: refresh_token,
+                user_id: String::new(),
+                expires_at: None,
+            }),
         }
+    }
 
-        // refresh tokenを元にaccess tokenを取得
-        self.update_access_token().unwrap();
+    fn has_refresh_token(&self) -> bool {
+        !self.state.read().unwrap().refresh_token.is_empty()
     }
 
-    fn update_access_token(&mut self) -> Result<(), ()> {
+    fn update_access_token(&self) -> Result<(), ()> {
+        let refresh_token = self.state.read().unwrap().refresh_token.clone();
         let post_body = format!("client_id={client_id}&redirect_uri={redirect_uri}&refresh_token={refresh_token}&grant_type=refresh_token",
                                 client_id = self.client_id,
                                 redirect_uri = DESKTOP_APP_URI,
-                                refresh_token = self.refresh_token);
-        self._update_access_token(post_body)
+                                refresh_token = refresh_token);
+        self.exchange_token(post_body)
+    }
+
+    fn exchange_authorization_code(&self, code: &str, redirect_uri: &str) -> Result<(), ()> {
+        let post_body = format!("client_id={client_id}&redirect_uri={redirect_uri}&grant_type=authorization_code&code={code}",
+                                client_id = self.client_id,
+                                redirect_uri = redirect_uri,
+                                code = code);
+        self.exchange_token(post_body)
     }
 
-    fn _update_access_token(&mut self, body: String) -> Result<(), ()> {
+    fn exchange_token(&self, body: String) -> Result<(), ()> {
         let mut res_body = String::new();
         try!(self.client
             .post("https://login.live.com/oauth20_token.srf")
@@ -83,12 +90,498 @@ impl<TConfig: Config> OneDriveClient<TConfig> {
         json::parse(res_body.as_str())
             .map_err(|_| ())
             .and_then(|v| {
-                self.user_id = try!(v["user_id"].as_str().ok_or(())).to_string();
-                self.expires_in = try!(v["expires_in"].as_u32().ok_or(()));
-                self.access_token = try!(v["access_token"].as_str().ok_or(())).to_string();
-                self.refresh_token = try!(v["refresh_token"].as_str().ok_or(())).to_string();
-                self.config.write().unwrap().set("onedrive.refresh_token", &self.refresh_token);
+                let user_id = try!(v["user_id"].as_str().ok_or(())).to_string();
+                let expires_in = try!(v["expires_in"].as_u32().ok_or(()));
+                let access_token = try!(v["access_token"].as_str().ok_or(())).to_string();
+                let refresh_token = try!(v["refresh_token"].as_str().ok_or(())).to_string();
+                self.config.write().unwrap().set_secret("onedrive.refresh_token", &refresh_token);
+
+                let margin = Duration::from_secs(TOKEN_EXPIRY_MARGIN_SECS);
+                let expires_at = Instant::now()
+                    .checked_add(Duration::from_secs(expires_in as u64))
+                    .and_then(|t| t.checked_sub(margin));
+
+                let mut state = self.state.write().unwrap();
+                state.user_id = user_id;
+                state.access_token = access_token;
+                state.refresh_token = refresh_token;
+                state.expires_at = expires_at;
                 Ok(())
             })
     }
+
+    fn ensure_access_token(&self) -> Result<(), ()> {
+        let needs_refresh = {
+            let state = self.state.read().unwrap();
+            state.access_token.is_empty() ||
+            state.expires_at.map_or(true, |t| Instant::now() >= t)
+        };
+        if needs_refresh {
+            self.update_access_token()
+        } else {
+            Ok(())
+        }
+    }
+
+    fn current_access_token(&self) -> String {
+        self.state.read().unwrap().access_token.clone()
+    }
+
+    fn authorized_request<F>(&self, mut send: F) -> ::std::io::Result<Response>
+        where F: FnMut(&str) -> ::hyper::Result<Response>
+    {
+        try!(self.ensure_access_token().map_err(|_| token_error()));
+        let token = self.current_access_token();
+        let res = try!(send(&token).map_err(io_err));
+        if res.status != StatusCode::Unauthorized {
+            return Ok(res);
+        }
+
+        // Got a 401: refresh the access token once and replay the request
+        try!(self.update_access_token().map_err(|_| token_error()));
+        let token = self.current_access_token();
+        send(&token).map_err(io_err)
+    }
+}
+
+fn io_err(e: ::hyper::Error) -> ::std::io::Error {
+    ::std::io::Error::new(::std::io::ErrorKind::Other, e)
+}
+
+fn token_error() -> ::std::io::Error {
+    ::std::io::Error::new(::std::io::ErrorKind::Other, "failed to obtain access token")
+}
+
+fn graph_error(message: &str, status: StatusCode) -> ::std::io::Error {
+    ::std::io::Error::new(::std::io::ErrorKind::Other, format!("{}: {}", message, status))
+}
+
+pub struct OneDriveClient<TConfig: Config> {
+    tokens: Arc<TokenManager<TConfig>>,
+}
+
+impl<TConfig: Config> OneDriveClient<TConfig> {
+    pub fn new(client_id: String, config: Arc<RwLock<TConfig>>) -> OneDriveClient<TConfig> {
+        OneDriveClient {
+            tokens: Arc::new(TokenManager::new(Arc::new(Client::new()), client_id, config)),
+        }
+    }
+
+    pub fn access_test(&self) {
+        if !self.tokens.has_refresh_token() {
+            login_via_loopback(&self.tokens).expect("interactive OneDrive login failed");
+            return;
+        }
+
+        // Exchange the stored refresh token for a fresh access token
+        self.tokens.update_access_token().unwrap();
+    }
+}
+
+// Listens on a local TCP socket for the OAuth redirect and captures the
+// authorization code automatically, replacing the old flow of writing the
+// URL to config.json and pasting the code back in by hand.
+fn login_via_loopback<TConfig: Config>(tokens: &TokenManager<TConfig>) -> Result<(), ()> {
+    let listener = try!(::std::net::TcpListener::bind("127.0.0.1:0").map_err(|_| ()));
+    let port = try!(listener.local_addr().map_err(|_| ())).port();
+    let redirect_uri = format!("http://127.0.0.1:{}/", port);
+    let authorize_url = format!("https://login.live.com/oauth20_authorize.\
+                                 srf?client_id={client_id}&scope={scope}&response_type=code&redirect_uri={redirect_uri}",
+                                client_id = tokens.client_id,
+                                scope = SCOPE,
+                                redirect_uri = percent_encode(&redirect_uri));
+
+    println!("Open the following URL in a browser to authenticate:\n{}", authorize_url);
+
+    let (stream, _) = try!(listener.accept().map_err(|_| ()));
+    let code = try!(read_authorization_code(stream));
+    tokens.exchange_authorization_code(&code, &redirect_uri)
+}
+
+// Reads only the first request made to the redirect target and extracts the "code=" query parameter
+fn read_authorization_code(stream: ::std::net::TcpStream) -> Result<String, ()> {
+    let mut reader = ::std::io::BufReader::new(try!(stream.try_clone().map_err(|_| ())));
+    let mut request_line = String::new();
+    try!(reader.read_line(&mut request_line).map_err(|_| ()));
+
+    // Extract the path and query from "GET /?code=XXXX&... HTTP/1.1"
+    let path = try!(request_line.split_whitespace().nth(1).ok_or(()));
+    let query = path.splitn(2, '?').nth(1).unwrap_or("");
+    let code = try!(query.split('&')
+        .filter_map(|kv| {
+            let mut parts = kv.splitn(2, '=');
+            match (parts.next(), parts.next()) {
+                (Some("code"), Some(v)) => Some(v.to_string()),
+                _ => None,
+            }
+        })
+        .next()
+        .ok_or(()));
+
+    let mut writer = stream;
+    let body = "<html><body>Authentication complete. You can close this tab.</body></html>";
+    let response = format!("HTTP/1.1 200 OK\r\nContent-Type: text/html; \
+                             charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                            body.len(),
+                            body);
+    let _ = writer.write_all(response.as_bytes());
+    Ok(code)
+}
+
+// Percent-encodes each segment of an item path and builds a "/drive/root:/{path}:{suffix}"
+// URL, or the path-less "/drive/root{suffix}" form, with the suffix's leading ':' dropped,
+// for the root item itself (an empty name would otherwise produce the malformed
+// "/drive/root:/:{suffix}").
+fn item_url(name: &str, suffix: &str) -> String {
+    if name.is_empty() {
+        let suffix = if suffix.starts_with(':') { &suffix[1..] } else { suffix };
+        return format!("{root}/root{suffix}", root = GRAPH_DRIVE_ROOT, suffix = suffix);
+    }
+    let encoded = name.split('/')
+        .map(percent_encode)
+        .collect::<Vec<_>>()
+        .join("/");
+    format!("{root}/root:/{path}:{suffix}",
+            root = GRAPH_DRIVE_ROOT,
+            path = encoded,
+            suffix = suffix)
+}
+
+fn percent_encode(segment: &str) -> String {
+    let mut out = String::with_capacity(segment.len());
+    for b in segment.bytes() {
+        match b {
+            b'A'...b'Z' | b'a'...b'z' | b'0'...b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+impl<TConfig: Config> ObjectStore for OneDriveClient<TConfig> {
+    type Reader = OneDriveReader;
+    type Writer = OneDriveWriter<TConfig>;
+    type ObjectIterator = OneDriveObjectIterator<TConfig>;
+
+    fn open(&self, name: &str) -> ::std::io::Result<Self::Reader> {
+        let url = item_url(name, ":/content");
+        let tokens = &self.tokens;
+        let mut res = try!(tokens.authorized_request(|token| {
+            tokens.client
+                .get(&url)
+                .header(Authorization(Bearer { token: token.to_string() }))
+                .send()
+        }));
+        if !res.status.is_success() {
+            return Err(graph_error("failed to open object", res.status));
+        }
+
+        let mut path = temp_dir_path();
+        path.push(temp_file_name());
+        let mut file = try!(File::create(&path));
+        try!(::std::io::copy(&mut res, &mut file));
+        try!(file.seek(SeekFrom::Start(0)));
+        Ok(OneDriveReader { file: file, path: path })
+    }
+
+    fn create(&self, name: &str) -> ::std::io::Result<Self::Writer> {
+        Ok(OneDriveWriter::new(self.tokens.clone(), name.to_string()))
+    }
+
+    fn remove(&self, name: &str) -> ::std::io::Result<()> {
+        let url = item_url(name, "");
+        let tokens = &self.tokens;
+        let res = try!(tokens.authorized_request(|token| {
+            tokens.client
+                .delete(&url)
+                .header(Authorization(Bearer { token: token.to_string() }))
+                .send()
+        }));
+        if !res.status.is_success() {
+            return Err(graph_error("failed to remove object", res.status));
+        }
+        Ok(())
+    }
+
+    fn list(&self, prefix: &str) -> ::std::io::Result<Self::ObjectIterator> {
+        let url = item_url(prefix, ":/children");
+        OneDriveObjectIterator::new(self.tokens.clone(), url)
+    }
+}
+
+/// Caches a GET body in a temp file so it can satisfy Read + Seek
+pub struct OneDriveReader {
+    file: File,
+    path: PathBuf,
+}
+
+impl Read for OneDriveReader {
+    fn read(&mut self, buf: &mut [u8]) -> ::std::io::Result<usize> {
+        self.file.read(buf)
+    }
+}
+
+impl Seek for OneDriveReader {
+    fn seek(&mut self, pos: SeekFrom) -> ::std::io::Result<u64> {
+        self.file.seek(pos)
+    }
+}
+
+impl Drop for OneDriveReader {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Buffers written bytes and, on drop, uploads small files with a single PUT
+/// or files over SMALL_FILE_THRESHOLD as chunked PUTs through an upload session.
+pub struct OneDriveWriter<TConfig: Config> {
+    tokens: Arc<TokenManager<TConfig>>,
+    path: String,
+    buffer: Vec<u8>,
+    closed: bool,
+}
+
+impl<TConfig: Config> OneDriveWriter<TConfig> {
+    fn new(tokens: Arc<TokenManager<TConfig>>, path: String) -> Self {
+        OneDriveWriter {
+            tokens: tokens,
+            path: path,
+            buffer: Vec::new(),
+            closed: false,
+        }
+    }
+
+    /// Uploads the buffered bytes and returns the result. Drop also calls
+    /// finish() as a best-effort fallback, but only close() lets a caller
+    /// observe a failed upload instead of having it silently discarded.
+    pub fn close(mut self) -> ::std::io::Result<()> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> ::std::io::Result<()> {
+        if self.closed {
+            return Ok(());
+        }
+        self.closed = true;
+        if (self.buffer.len() as u64) <= SMALL_FILE_THRESHOLD {
+            self.put_whole()
+        } else {
+            self.put_chunked()
+        }
+    }
+
+    fn put_whole(&mut self) -> ::std::io::Result<()> {
+        let url = item_url(&self.path, ":/content");
+        let tokens = &self.tokens;
+        let buffer = &self.buffer;
+        let res = try!(tokens.authorized_request(|token| {
+            tokens.client
+                .put(&url)
+                .header(Authorization(Bearer { token: token.to_string() }))
+                .header(ContentType("application/octet-stream".parse().unwrap()))
+                .body(buffer.as_slice())
+                .send()
+        }));
+        if !res.status.is_success() {
+            return Err(graph_error("failed to upload object", res.status));
+        }
+        Ok(())
+    }
+
+    fn create_upload_session(&self) -> ::std::io::Result<String> {
+        let url = item_url(&self.path, ":/createUploadSession");
+        let tokens = &self.tokens;
+        let mut res = try!(tokens.authorized_request(|token| {
+            tokens.client
+                .post(&url)
+                .header(Authorization(Bearer { token: token.to_string() }))
+                .header(ContentType::json())
+                .body("{}")
+                .send()
+        }));
+        let mut res_body = String::new();
+        try!(res.read_to_string(&mut res_body));
+        let parsed = try!(json::parse(&res_body)
+            .map_err(|e| ::std::io::Error::new(::std::io::ErrorKind::Other, e)));
+        parsed["uploadUrl"]
+            .as_str()
+            .map(|s| s.to_string())
+            .ok_or_else(|| {
+                ::std::io::Error::new(::std::io::ErrorKind::Other, "no uploadUrl in response")
+            })
+    }
+
+    fn put_chunked(&mut self) -> ::std::io::Result<()> {
+        let upload_url = try!(self.create_upload_session());
+        let total = self.buffer.len() as u64;
+        let mut offset = 0u64;
+        while offset < total {
+            let end = ::std::cmp::min(offset + UPLOAD_CHUNK_SIZE, total);
+            offset = try!(self.put_chunk_with_retry(&upload_url, offset, end, total));
+        }
+        Ok(())
+    }
+
+    fn put_chunk_with_retry(&self,
+                             upload_url: &str,
+                             start: u64,
+                             end: u64,
+                             total: u64)
+                             -> ::std::io::Result<u64> {
+        let mut last_err = None;
+        for _ in 0..UPLOAD_CHUNK_RETRIES {
+            match self.put_chunk(upload_url, start, end, total) {
+                Ok(next_offset) => return Ok(next_offset),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        Err(last_err.unwrap_or_else(|| {
+            ::std::io::Error::new(::std::io::ErrorKind::Other, "chunk upload failed")
+        }))
+    }
+
+    // No Authorization header needed: the upload session URL itself embeds the token
+    fn put_chunk(&self, upload_url: &str, start: u64, end: u64, total: u64) -> ::std::io::Result<u64> {
+        let chunk = &self.buffer[start as usize..end as usize];
+        let mut res = try!(self.tokens
+            .client
+            .put(upload_url)
+            .header(::hyper::header::ContentRange(::hyper::header::ContentRangeSpec::Bytes {
+                range: Some((start, end - 1)),
+                instance_length: Some(total),
+            }))
+            .body(chunk)
+            .send()
+            .map_err(io_err));
+
+        let is_final = end == total;
+        if is_final {
+            if res.status.is_success() {
+                return Ok(end);
+            }
+            return Err(::std::io::Error::new(::std::io::ErrorKind::Other,
+                                              "final chunk upload failed"));
+        }
+
+        if !res.status.is_success() {
+            return Err(::std::io::Error::new(::std::io::ErrorKind::Other,
+                                              format!("chunk upload failed: {}", res.status)));
+        }
+
+        let mut res_body = String::new();
+        try!(res.read_to_string(&mut res_body));
+        let parsed = try!(json::parse(&res_body)
+            .map_err(|e| ::std::io::Error::new(::std::io::ErrorKind::Other, e)));
+        match parsed["nextExpectedRanges"][0].as_str() {
+            Some(range) => {
+                let start_str = range.split('-').next().unwrap_or("");
+                start_str.parse::<u64>().map_err(|_| {
+                    ::std::io::Error::new(::std::io::ErrorKind::Other,
+                                          "malformed nextExpectedRanges")
+                })
+            }
+            None => Ok(end),
+        }
+    }
+}
+
+impl<TConfig: Config> Write for OneDriveWriter<TConfig> {
+    fn write(&mut self, buf: &[u8]) -> ::std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> ::std::io::Result<()> {
+        // Nothing to flush: writes only accumulate in `buffer` until the upload
+        // is finalized on drop. Finalizing here would permanently mark the
+        // writer closed, silently dropping any bytes written after flush().
+        Ok(())
+    }
+}
+
+impl<TConfig: Config> Drop for OneDriveWriter<TConfig> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+/// Holds one page of list() results and fetches the next page via
+/// @odata.nextLink only when it's actually needed
+pub struct OneDriveObjectIterator<TConfig: Config> {
+    tokens: Arc<TokenManager<TConfig>>,
+    current: ::std::vec::IntoIter<String>,
+    next_link: Option<String>,
+}
+
+impl<TConfig: Config> OneDriveObjectIterator<TConfig> {
+    fn new(tokens: Arc<TokenManager<TConfig>>, url: String) -> ::std::io::Result<Self> {
+        let mut iter = OneDriveObjectIterator {
+            tokens: tokens,
+            current: Vec::new().into_iter(),
+            next_link: Some(url),
+        };
+        try!(iter.fetch_next_page());
+        Ok(iter)
+    }
+
+    fn fetch_next_page(&mut self) -> ::std::io::Result<()> {
+        let url = match self.next_link.take() {
+            Some(url) => url,
+            None => return Ok(()),
+        };
+        let tokens = &self.tokens;
+        let mut res = try!(tokens.authorized_request(|token| {
+            tokens.client
+                .get(&url)
+                .header(Authorization(Bearer { token: token.to_string() }))
+                .send()
+        }));
+        if !res.status.is_success() {
+            return Err(graph_error("failed to list objects", res.status));
+        }
+        let mut res_body = String::new();
+        try!(res.read_to_string(&mut res_body));
+        let parsed = try!(json::parse(&res_body)
+            .map_err(|e| ::std::io::Error::new(::std::io::ErrorKind::Other, e)));
+        let names = parsed["value"]
+            .members()
+            .filter_map(|v| v["name"].as_str().map(|s| s.to_string()))
+            .collect::<Vec<_>>();
+        self.next_link = parsed["@odata.nextLink"].as_str().map(|s| s.to_string());
+        self.current = names.into_iter();
+        Ok(())
+    }
+}
+
+impl<TConfig: Config> Iterator for OneDriveObjectIterator<TConfig> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        loop {
+            if let Some(name) = self.current.next() {
+                return Some(name);
+            }
+            if self.next_link.is_none() {
+                return None;
+            }
+            if self.fetch_next_page().is_err() {
+                return None;
+            }
+        }
+    }
+}
+
+static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+fn temp_dir_path() -> PathBuf {
+    ::std::env::temp_dir()
+}
+
+fn temp_file_name() -> String {
+    format!("sciurus-onedrive-{}-{}",
+            ::std::process::id(),
+            TEMP_FILE_COUNTER.fetch_add(1, Ordering::SeqCst))
 }